@@ -1,13 +1,15 @@
 use std::borrow::Cow;
+use std::env;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write};
 use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
 use std::sync::atomic::Ordering::Relaxed;
 
 use yansi::Color::Green;
 use yansi::{Paint, Style};
 
-use crate::config::Config;
+use crate::config::{Config, OutputFormat};
 use crate::error::{Error, ErrorKind, Result};
 use crate::util::{warnln, PagePathExt};
 
@@ -16,73 +18,82 @@ const DESC: &str = "> ";
 const BULLET: &str = "- ";
 const EXAMPLE: char = '`';
 
-/// Highlight a substring between `start` and `end` inside `s` and return a new `String`.
-fn highlight(start: &str, end: &str, s: &str, style_normal: Style, style_hl: Style) -> String {
-    let split: Vec<&str> = s.split(start).collect();
-    // Highlight beginning not found.
-    if split.len() == 1 {
-        return style_normal.paint(s).to_string();
-    }
-
-    let mut buf = String::new();
-
-    if start == end {
-        for (i, part) in split.into_iter().enumerate() {
-            // Only odd indexes contain the part to be highlighted.
-            // "aa `bb` cc `dd` ee"
-            // 0: "aa "
-            // 1: "bb"      (highlighted)
-            // 2: " cc "
-            // 3: "dd"      (highlighted)
-            // 4: " ee"
-            if i % 2 == 0 {
-                buf += &style_normal.paint(part).to_string();
-            } else {
-                buf += &style_hl.paint(part).to_string();
-            }
-        }
+/// The kind of inline span produced by [`tokenize`].
+#[derive(Clone, Copy)]
+enum SpanKind {
+    /// Plain text, painted with the block's base style.
+    Normal,
+    /// Inline code delimited by single backticks.
+    Code,
+    /// A URL delimited by `<http…>`.
+    Url,
+    /// A placeholder delimited by `{{…}}`.
+    Placeholder,
+}
 
-        return buf;
+/// Find the end of a placeholder whose opening `{{` has already been consumed.
+///
+/// Returns the byte length of the contents and the total number of bytes consumed
+/// (including the `{{` and `}}`). A run of more than two closing braces is treated
+/// as content followed by the final two braces, so `{{a}}}` yields the contents `a}`.
+fn placeholder_end(inner: &str) -> Option<(usize, usize)> {
+    let mut end = inner.find("}}")?;
+    while inner[end + 2..].starts_with('}') {
+        end += 1;
     }
+    Some((end, 2 + end + 2))
+}
 
-    for part in split {
-        if part.contains(end) {
-            // The first part of the second split contains the part to be highlighted.
-
-            if end == ">" {
-                // "More information: <https://example.com>."
-                // 0: "More information: " => does not match
-                // 1: "s://example.com>."  => 0: "s://example.com" (highlighted)
-                //                            1: ">."
-                let part_split = part.split_once('>').unwrap();
+/// Scan `s` once and split it into ordered inline spans.
+///
+/// The scanner tracks four span kinds — normal text, inline code, URLs and
+/// placeholders — in a single pass. `\{\{` and `\}\}` are emitted as literal braces
+/// rather than placeholder boundaries, and an unterminated marker is left as plain
+/// text instead of panicking.
+fn tokenize(s: &str) -> Vec<(SpanKind, &str)> {
+    let mut spans = Vec::new();
+    let mut i = 0;
+    let mut normal_start = 0;
+
+    while i < s.len() {
+        let rest = &s[i..];
+
+        // `(kind, contents, consumed bytes)` if a span starts here, else `None`.
+        let span = if rest.starts_with("\\{") || rest.starts_with("\\}") {
+            // Escaped brace: emit the single literal brace, dropping the backslash.
+            Some((SpanKind::Normal, &s[i + 1..i + 2], 2))
+        } else if rest.starts_with("{{") {
+            placeholder_end(&s[i + 2..])
+                .map(|(len, total)| (SpanKind::Placeholder, &s[i + 2..i + 2 + len], total))
+        } else if rest.starts_with("<http") {
+            rest.find('>')
+                .map(|end| (SpanKind::Url, &s[i + 1..i + end], end + 1))
+        } else if rest.starts_with('`') {
+            rest[1..]
+                .find('`')
+                .map(|end| (SpanKind::Code, &s[i + 1..i + 1 + end], end + 2))
+        } else {
+            None
+        };
 
-                // "<http" is used to detect URLs. It must be added back.
-                let hl = format!("http{}", part_split.0);
-                buf += &style_hl.paint(hl).to_string();
-                buf += &style_normal.paint(part_split.1).to_string();
-            } else {
-                // "aa bb {{cc}} {{dd}} ee"
-                // 0: "aa bb "   => does not match
-                // 1: "cc}} "    => 0: "cc"    (highlighted)
-                //                  1: "}}"
-                // 2: "dd}} ee"  => 0: "dd"    (highlighted)
-                //                  1: "}} ee"
-
-                // This is required for special cases with three closing curly braces ("}}}").
-                // The first brace is inside the placeholder, and the last two mark the end of it.
-                let idx = part.rmatch_indices(end).last().unwrap().0;
-                let part_spl = part.split_at(idx);
-
-                buf += &style_hl.paint(part_spl.0).to_string();
-                buf += &style_normal.paint(&part_spl.1[2..]).to_string();
+        if let Some((kind, contents, consumed)) = span {
+            if normal_start < i {
+                spans.push((SpanKind::Normal, &s[normal_start..i]));
             }
+            spans.push((kind, contents));
+            i += consumed;
+            normal_start = i;
         } else {
-            // Highlight ending not found.
-            buf += &style_normal.paint(part).to_string();
+            // Not a marker (or an unterminated one): part of the current normal run.
+            i += rest.chars().next().unwrap().len_utf8();
         }
     }
 
-    buf
+    if normal_start < s.len() {
+        spans.push((SpanKind::Normal, &s[normal_start..]));
+    }
+
+    spans
 }
 
 struct RenderStyles {
@@ -93,6 +104,68 @@ struct RenderStyles {
     url: Style,
     inline_code: Style,
     placeholder: Style,
+    search_match: Style,
+}
+
+/// Locates occurrences of the `--highlight` pattern within a span of text.
+///
+/// Matching is case-insensitive for ASCII, which keeps byte offsets aligned with
+/// the original string so matched ranges can be painted in place.
+struct SearchMatcher {
+    needle: String,
+}
+
+impl SearchMatcher {
+    fn new(pattern: &str) -> Self {
+        Self {
+            needle: pattern.to_string(),
+        }
+    }
+
+    /// Return the byte ranges of every non-overlapping match within `haystack`.
+    fn find_all(&self, haystack: &str) -> Vec<(usize, usize)> {
+        let needle = self.needle.as_bytes();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let bytes = haystack.as_bytes();
+        let mut matches = Vec::new();
+        let mut i = 0;
+        while i + needle.len() <= bytes.len() {
+            if bytes[i..i + needle.len()].eq_ignore_ascii_case(needle)
+                && haystack.is_char_boundary(i)
+                && haystack.is_char_boundary(i + needle.len())
+            {
+                matches.push((i, i + needle.len()));
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+
+        matches
+    }
+}
+
+/// A sink that turns the classified lines of a tldr page into a concrete output format.
+///
+/// [`PageRenderer::render`] classifies every line and drives the sink; the sink decides
+/// how a title, description, bullet or example is emitted (ANSI terminal text, HTML,
+/// JSON, ...). Inline markup (placeholders, URLs and inline code) is left to the sink.
+trait PageSink {
+    /// Emit the page title (a `# ` line), with the leading marker stripped.
+    fn title(&mut self, title: &str) -> Result<()>;
+    /// Emit a description line (a `> ` line).
+    fn description(&mut self, desc: &str) -> Result<()>;
+    /// Emit an example description (a `- ` bullet point).
+    fn bullet(&mut self, bullet: &str) -> Result<()>;
+    /// Emit an example command (the text between the surrounding backticks).
+    fn example(&mut self, command: &str) -> Result<()>;
+    /// Emit a blank line separating blocks.
+    fn newline(&mut self) -> Result<()>;
+    /// Flush any buffered output; called once after the last line of the page.
+    fn finish(&mut self) -> Result<()>;
 }
 
 pub struct PageRenderer<'a> {
@@ -100,16 +173,12 @@ pub struct PageRenderer<'a> {
     path: &'a Path,
     /// A BufReader containing the page.
     reader: BufReader<File>,
-    /// A buffered handle to standard output.
-    stdout: BufWriter<io::StdoutLock<'static>>,
     /// The line of the page that is currently being worked with.
     current_line: String,
     /// The line number of the current line.
     lnum: usize,
-    /// Style configuration.
-    style: RenderStyles,
-    /// Other options.
-    cfg: &'a Config,
+    /// The sink the classified lines are written to.
+    sink: Box<dyn PageSink + 'a>,
 }
 
 impl<'a> PageRenderer<'a> {
@@ -125,24 +194,75 @@ impl<'a> PageRenderer<'a> {
             return Ok(());
         }
 
-        Self {
+        // Hand off to a pager for interactive output, mirroring how diff filters
+        // pipe into `less`. Raw markdown is handled above, so we never page it.
+        let mut pager = Self::spawn_pager(cfg);
+        let sink: Box<dyn Write> = match pager.as_mut() {
+            // `stdin` is always piped when we spawn the pager, so this is safe to unwrap.
+            Some(child) => Box::new(child.stdin.take().unwrap()),
+            None => Box::new(io::stdout().lock()),
+        };
+
+        let out = BufWriter::new(sink);
+        let sink: Box<dyn PageSink + 'a> = match cfg.output.format {
+            OutputFormat::Ansi => Box::new(TerminalSink::new(out, path, cfg)),
+            OutputFormat::Html => Box::new(HtmlSink::new(out)),
+            OutputFormat::Json => Box::new(JsonSink::new(out, path)),
+            OutputFormat::Roff => Box::new(RoffSink::new(out, path)),
+        };
+
+        let result = Self {
             path,
             reader: BufReader::new(page),
-            stdout: BufWriter::new(io::stdout().lock()),
             current_line: String::new(),
             lnum: 0,
-            style: RenderStyles {
-                title: cfg.style.title.into(),
-                desc: cfg.style.description.into(),
-                bullet: cfg.style.bullet.into(),
-                example: cfg.style.example.into(),
-                url: cfg.style.url.into(),
-                inline_code: cfg.style.inline_code.into(),
-                placeholder: cfg.style.placeholder.into(),
-            },
-            cfg,
+            sink,
+        }
+        .render();
+
+        // Close the pipe and wait for the pager to exit before returning, otherwise
+        // the shell prompt would be printed on top of it.
+        if let Some(mut child) = pager {
+            child.wait().map_err(|e| {
+                Error::new(format!("failed to wait for pager: {e}")).kind(ErrorKind::Io)
+            })?;
+        }
+
+        result
+    }
+
+    /// Spawn the configured pager, returning `None` if paging is disabled.
+    ///
+    /// Paging is skipped when `--no-pager` is set or when standard output is not a
+    /// terminal (e.g. redirected to a file or piped into another program), so that
+    /// scripting keeps working. The command defaults to `$PAGER`, falling back to
+    /// `less -R` so that ANSI colors are preserved.
+    fn spawn_pager(cfg: &Config) -> Option<Child> {
+        if cfg.output.no_pager || !io::stdout().is_terminal() {
+            return None;
+        }
+
+        let pager = cfg
+            .output
+            .pager
+            .clone()
+            .or_else(|| env::var("PAGER").ok())
+            .unwrap_or_else(|| "less -R".to_string());
+
+        let mut parts = pager.split_whitespace();
+        let program = parts.next()?;
+
+        match Command::new(program)
+            .args(parts)
+            .stdin(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => Some(child),
+            Err(e) => {
+                warnln!("could not run pager '{pager}': {e}");
+                None
+            }
         }
-        .render()
     }
 
     /// Print the first page that was found and warnings for every other page.
@@ -182,28 +302,133 @@ impl<'a> PageRenderer<'a> {
             .map_err(|e| Error::new(format!("'{}': {e}", self.path.display())))
     }
 
-    /// Write the current line to the page buffer as a title.
-    fn add_title(&mut self) -> Result<()> {
+    /// Classify each line of the page and drive the configured sink.
+    fn render(&mut self) -> Result<()> {
+        while self.next_line()? != 0 {
+            // The line is moved out so that the sink (also behind `&mut self`) can be
+            // borrowed mutably at the same time; `next_line` clears the buffer anyway.
+            let line = std::mem::take(&mut self.current_line);
+
+            if let Some(title) = line.strip_prefix(TITLE) {
+                self.sink.title(title)?;
+            } else if let Some(desc) = line.strip_prefix(DESC) {
+                self.sink.description(desc)?;
+            } else if let Some(bullet) = line.strip_prefix(BULLET) {
+                self.sink.bullet(bullet)?;
+            } else if let Some(rest) = line.strip_prefix(EXAMPLE) {
+                let command = rest.trim_end().strip_suffix('`').ok_or_else(|| {
+                    Error::parse_page(self.path, self.lnum, &line)
+                        .describe("\nEvery line with an example must end with a backtick '`'.")
+                })?;
+                self.sink.example(command)?;
+            } else if line.chars().all(char::is_whitespace) {
+                self.sink.newline()?;
+            } else {
+                return Err(Error::parse_page(self.path, self.lnum, &line).describe(
+                    "\nEvery non-empty line must begin with either '# ', '> ', '- ' or '`'.",
+                ));
+            }
+        }
+
+        self.sink.newline()?;
+        self.sink.finish()
+    }
+}
+
+/// Sink that renders the page as ANSI-styled text for a terminal.
+struct TerminalSink<'a> {
+    out: BufWriter<Box<dyn Write>>,
+    style: RenderStyles,
+    /// Pattern to emphasize, from `--highlight`.
+    search: Option<SearchMatcher>,
+    path: &'a Path,
+    cfg: &'a Config,
+}
+
+impl<'a> TerminalSink<'a> {
+    fn new(out: BufWriter<Box<dyn Write>>, path: &'a Path, cfg: &'a Config) -> Self {
+        Self {
+            out,
+            style: RenderStyles {
+                title: cfg.style.title.into(),
+                desc: cfg.style.description.into(),
+                bullet: cfg.style.bullet.into(),
+                example: cfg.style.example.into(),
+                url: cfg.style.url.into(),
+                inline_code: cfg.style.inline_code.into(),
+                placeholder: cfg.style.placeholder.into(),
+                search_match: cfg.style.search_match.into(),
+            },
+            search: cfg.output.highlight.as_deref().map(SearchMatcher::new),
+            path,
+            cfg,
+        }
+    }
+
+    /// Paint the inline spans of `line`, using `base` for normal text and the
+    /// configured styles for code, URLs and placeholders.
+    fn paint_spans(&mut self, line: &str, base: Style) -> Result<()> {
+        for (kind, text) in tokenize(line) {
+            let style = match kind {
+                SpanKind::Normal => base,
+                SpanKind::Code => self.style.inline_code,
+                SpanKind::Url => self.style.url,
+                SpanKind::Placeholder => self.style.placeholder,
+            };
+            self.paint_span(text, style)?;
+        }
+        Ok(())
+    }
+
+    /// Paint `text` in `style`, overlaying the search-match style on any portions
+    /// that match the `--highlight` pattern.
+    fn paint_span(&mut self, text: &str, style: Style) -> Result<()> {
+        let matches = match &self.search {
+            Some(matcher) => matcher.find_all(text),
+            None => {
+                write!(self.out, "{}", style.paint(text))?;
+                return Ok(());
+            }
+        };
+
+        let search_match = self.style.search_match;
+        let mut last = 0;
+        for (start, end) in matches {
+            if last < start {
+                write!(self.out, "{}", style.paint(&text[last..start]))?;
+            }
+            write!(self.out, "{}", search_match.paint(&text[start..end]))?;
+            last = end;
+        }
+        if last < text.len() {
+            write!(self.out, "{}", style.paint(&text[last..]))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PageSink for TerminalSink<'_> {
+    fn title(&mut self, title: &str) -> Result<()> {
         if !self.cfg.output.show_title {
             return Ok(());
         }
         if !self.cfg.output.compact {
-            writeln!(self.stdout)?;
+            writeln!(self.out)?;
         }
 
-        let line = self.current_line.strip_prefix(TITLE).unwrap();
         let title = if self.cfg.output.platform_title {
             if let Some(platform) = self.path.page_platform() {
-                Cow::Owned(format!("{platform}/{line}"))
+                Cow::Owned(format!("{platform}/{title}"))
             } else {
-                Cow::Borrowed(line)
+                Cow::Borrowed(title)
             }
         } else {
-            Cow::Borrowed(line)
+            Cow::Borrowed(title)
         };
 
         write!(
-            self.stdout,
+            self.out,
             "{}{}",
             " ".repeat(self.cfg.indent.title),
             self.style.title.paint(title)
@@ -212,124 +437,418 @@ impl<'a> PageRenderer<'a> {
         Ok(())
     }
 
-    /// Write the current line to the page buffer as a description.
-    fn add_desc(&mut self) -> Result<()> {
-        write!(
-            self.stdout,
-            "{}{}",
-            " ".repeat(self.cfg.indent.description),
-            highlight(
-                "`",
-                "`",
-                &highlight(
-                    "<http",
-                    ">",
-                    self.current_line.strip_prefix(DESC).unwrap(),
-                    self.style.desc,
-                    self.style.url,
-                ),
-                self.style.desc,
-                self.style.inline_code,
-            )
-        )?;
-
-        Ok(())
+    fn description(&mut self, desc: &str) -> Result<()> {
+        write!(self.out, "{}", " ".repeat(self.cfg.indent.description))?;
+        let base = self.style.desc;
+        self.paint_spans(desc, base)
     }
 
-    /// Write the current line to the page buffer as a bullet point.
-    fn add_bullet(&mut self) -> Result<()> {
+    fn bullet(&mut self, bullet: &str) -> Result<()> {
         let line = if self.cfg.output.show_hyphens {
-            self.current_line
-                .replace_range(..2, &self.cfg.output.example_prefix);
-            &self.current_line
+            Cow::Owned(format!("{}{bullet}", self.cfg.output.example_prefix))
         } else {
-            self.current_line.strip_prefix(BULLET).unwrap()
+            Cow::Borrowed(bullet)
         };
 
-        write!(
-            self.stdout,
-            "{}{}",
-            " ".repeat(self.cfg.indent.bullet),
-            highlight(
-                "`",
-                "`",
-                &highlight("<http", ">", line, self.style.bullet, self.style.url),
-                self.style.bullet,
-                self.style.inline_code,
-            )
-        )?;
+        write!(self.out, "{}", " ".repeat(self.cfg.indent.bullet))?;
+        let base = self.style.bullet;
+        self.paint_spans(&line, base)
+    }
+
+    fn example(&mut self, command: &str) -> Result<()> {
+        write!(self.out, "{}", " ".repeat(self.cfg.indent.example))?;
+        let base = self.style.example;
+        self.paint_spans(command, base)?;
+        writeln!(self.out)?;
+        Ok(())
+    }
+
+    fn newline(&mut self) -> Result<()> {
+        if !self.cfg.output.compact {
+            writeln!(self.out)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(self.out.flush()?)
+    }
+}
 
+/// Sink that renders the page as a semantic HTML fragment.
+struct HtmlSink {
+    out: BufWriter<Box<dyn Write>>,
+    /// Whether a `<blockquote>` is currently open.
+    in_blockquote: bool,
+    /// Whether a `<ul>` is currently open.
+    in_list: bool,
+    /// Whether an `<li>` is currently open.
+    in_item: bool,
+}
+
+impl HtmlSink {
+    fn new(out: BufWriter<Box<dyn Write>>) -> Self {
+        Self {
+            out,
+            in_blockquote: false,
+            in_list: false,
+            in_item: false,
+        }
+    }
+
+    fn close_blockquote(&mut self) -> Result<()> {
+        if self.in_blockquote {
+            writeln!(self.out, "</blockquote>")?;
+            self.in_blockquote = false;
+        }
+        Ok(())
+    }
+
+    fn close_list(&mut self) -> Result<()> {
+        if self.in_item {
+            writeln!(self.out, "  </li>")?;
+            self.in_item = false;
+        }
+        if self.in_list {
+            writeln!(self.out, "</ul>")?;
+            self.in_list = false;
+        }
+        Ok(())
+    }
+
+    fn close_blocks(&mut self) -> Result<()> {
+        self.close_blockquote()?;
+        self.close_list()
+    }
+}
+
+impl PageSink for HtmlSink {
+    fn title(&mut self, title: &str) -> Result<()> {
+        self.close_blocks()?;
+        writeln!(self.out, "<h1>{}</h1>", escape_html(title.trim()))?;
         Ok(())
     }
 
-    /// Write the current line to the page buffer as an example.
-    fn add_example(&mut self) -> Result<()> {
-        // Add spaces around escaped curly braces in order not to
-        // interpret them as a placeholder (e.g. in "\{\{{{ }}\}\}").
-        self.current_line = self
-            .current_line
-            .replace("\\{\\{", " \\{\\{ ")
-            .replace("\\}\\}", " \\}\\} ");
+    fn description(&mut self, desc: &str) -> Result<()> {
+        self.close_list()?;
+        if !self.in_blockquote {
+            writeln!(self.out, "<blockquote>")?;
+            self.in_blockquote = true;
+        }
+        writeln!(self.out, "  <p>{}</p>", render_html_spans(desc.trim()))?;
+        Ok(())
+    }
 
+    fn bullet(&mut self, bullet: &str) -> Result<()> {
+        self.close_blockquote()?;
+        if !self.in_list {
+            writeln!(self.out, "<ul>")?;
+            self.in_list = true;
+        }
+        if self.in_item {
+            writeln!(self.out, "  </li>")?;
+        }
+        writeln!(self.out, "  <li>")?;
+        writeln!(self.out, "    <p>{}</p>", render_html_spans(bullet.trim()))?;
+        self.in_item = true;
+        Ok(())
+    }
+
+    fn example(&mut self, command: &str) -> Result<()> {
+        // An example always belongs to the preceding bullet; start a bare item if
+        // the page somehow has none.
+        if !self.in_list {
+            writeln!(self.out, "<ul>")?;
+            self.in_list = true;
+        }
+        if !self.in_item {
+            writeln!(self.out, "  <li>")?;
+            self.in_item = true;
+        }
         writeln!(
-            self.stdout,
-            "{}{}",
-            " ".repeat(self.cfg.indent.example),
-            highlight(
-                "{{",
-                "}}",
-                self.current_line
-                    .strip_prefix(EXAMPLE)
-                    .unwrap()
-                    .trim_end()
-                    .strip_suffix('`')
-                    .ok_or_else(|| {
-                        Error::parse_page(self.path, self.lnum, &self.current_line)
-                            .describe("\nEvery line with an example must end with a backtick '`'.")
-                    })?,
-                self.style.example,
-                self.style.placeholder,
-            )
-            // Remove the extra spaces and backslashes.
-            .replace(" \\{\\{ ", "{{")
-            .replace(" \\}\\} ", "}}")
+            self.out,
+            "    <pre><code>{}</code></pre>",
+            render_html_spans(command)
         )?;
+        Ok(())
+    }
 
+    fn newline(&mut self) -> Result<()> {
+        // Blank lines carry no structure in HTML; the block tags do.
         Ok(())
     }
 
-    /// Write a newline to the page buffer if compact mode is not turned on.
-    fn add_newline(&mut self) -> Result<()> {
-        if !self.cfg.output.compact {
-            writeln!(self.stdout)?;
+    fn finish(&mut self) -> Result<()> {
+        self.close_blocks()?;
+        Ok(self.out.flush()?)
+    }
+}
+
+/// Sink that renders the page as a structured JSON document.
+struct JsonSink {
+    out: BufWriter<Box<dyn Write>>,
+    name: String,
+    platform: String,
+    description: Vec<String>,
+    /// Collected `(description, command)` example pairs.
+    examples: Vec<(String, String)>,
+    /// The most recent bullet point, awaiting its command.
+    pending: Option<String>,
+}
+
+impl JsonSink {
+    fn new(out: BufWriter<Box<dyn Write>>, path: &Path) -> Self {
+        Self {
+            out,
+            name: path.page_name().unwrap_or_default().to_string(),
+            platform: path.page_platform().unwrap_or_default().to_string(),
+            description: Vec::new(),
+            examples: Vec::new(),
+            pending: None,
         }
+    }
+}
 
+impl PageSink for JsonSink {
+    fn title(&mut self, title: &str) -> Result<()> {
+        self.name = title.trim().to_string();
         Ok(())
     }
 
-    /// Render the page to standard output.
-    fn render(&mut self) -> Result<()> {
-        while self.next_line()? != 0 {
-            if self.current_line.starts_with(TITLE) {
-                self.add_title()?;
-            } else if self.current_line.starts_with(DESC) {
-                self.add_desc()?;
-            } else if self.current_line.starts_with(BULLET) {
-                self.add_bullet()?;
-            } else if self.current_line.starts_with(EXAMPLE) {
-                self.add_example()?;
-            } else if self.current_line.chars().all(char::is_whitespace) {
-                self.add_newline()?;
-            } else {
-                return Err(
-                    Error::parse_page(self.path, self.lnum, &self.current_line).describe(
-                        "\nEvery non-empty line must begin with either '# ', '> ', '- ' or '`'.",
-                    ),
-                );
+    fn description(&mut self, desc: &str) -> Result<()> {
+        self.description.push(strip_markup(desc.trim()));
+        Ok(())
+    }
+
+    fn bullet(&mut self, bullet: &str) -> Result<()> {
+        self.pending = Some(strip_markup(bullet.trim()));
+        Ok(())
+    }
+
+    fn example(&mut self, command: &str) -> Result<()> {
+        // Commands keep their `{{placeholders}}` so consumers can fill them in.
+        self.examples
+            .push((self.pending.take().unwrap_or_default(), command.to_string()));
+        Ok(())
+    }
+
+    fn newline(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        write!(
+            self.out,
+            "{{\"name\":\"{}\",\"platform\":\"{}\",\"description\":\"{}\",\"examples\":[",
+            json_escape(&self.name),
+            json_escape(&self.platform),
+            json_escape(&self.description.join(" ")),
+        )?;
+        for (i, (desc, command)) in self.examples.iter().enumerate() {
+            if i != 0 {
+                write!(self.out, ",")?;
+            }
+            write!(
+                self.out,
+                "{{\"description\":\"{}\",\"command\":\"{}\"}}",
+                json_escape(desc),
+                json_escape(command),
+            )?;
+        }
+        writeln!(self.out, "]}}")?;
+        Ok(self.out.flush()?)
+    }
+}
+
+/// Sink that renders the page as roff source for `man`/`groff`.
+struct RoffSink {
+    out: BufWriter<Box<dyn Write>>,
+    name: String,
+    platform: String,
+    /// Whether the `.TH`/`.SH NAME` header has already been emitted.
+    header_written: bool,
+    /// Whether the `.SH DESCRIPTION` heading has already been emitted.
+    in_description: bool,
+}
+
+impl RoffSink {
+    fn new(out: BufWriter<Box<dyn Write>>, path: &Path) -> Self {
+        Self {
+            out,
+            name: path.page_name().unwrap_or_default().to_string(),
+            platform: path.page_platform().unwrap_or_default().to_string(),
+            header_written: false,
+            in_description: false,
+        }
+    }
+
+    /// Emit the `.TH` title header and `NAME` section once, before any body.
+    fn write_header(&mut self) -> Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+        writeln!(
+            self.out,
+            ".TH \"{}\" 1 \"\" \"tldr\" \"{}\"",
+            self.name.to_uppercase(),
+            self.platform,
+        )?;
+        writeln!(self.out, ".SH NAME")?;
+        writeln!(self.out, "{} \\- tldr page", escape_roff(&self.name))?;
+        self.header_written = true;
+        Ok(())
+    }
+}
+
+impl PageSink for RoffSink {
+    fn title(&mut self, title: &str) -> Result<()> {
+        self.name = title.trim().to_string();
+        self.write_header()
+    }
+
+    fn description(&mut self, desc: &str) -> Result<()> {
+        self.write_header()?;
+        if !self.in_description {
+            writeln!(self.out, ".SH DESCRIPTION")?;
+            self.in_description = true;
+        }
+        writeln!(self.out, "{}", roff_guard(&render_roff_spans(desc.trim())))?;
+        Ok(())
+    }
+
+    fn bullet(&mut self, bullet: &str) -> Result<()> {
+        self.write_header()?;
+        writeln!(self.out, ".TP")?;
+        writeln!(self.out, "{}", roff_guard(&render_roff_spans(bullet.trim())))?;
+        Ok(())
+    }
+
+    fn example(&mut self, command: &str) -> Result<()> {
+        self.write_header()?;
+        // `.nf`/`.fi` print the command verbatim, without filling or hyphenation.
+        writeln!(self.out, ".nf")?;
+        writeln!(self.out, "{}", roff_guard(&render_roff_spans(command)))?;
+        writeln!(self.out, ".fi")?;
+        Ok(())
+    }
+
+    fn newline(&mut self) -> Result<()> {
+        // Blank lines carry no structure in roff; the requests drive it.
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(self.out.flush()?)
+    }
+}
+
+/// Escape the characters that are special in roff text.
+fn escape_roff(s: &str) -> String {
+    // A literal backslash is the only in-line escape character in roff.
+    s.replace('\\', "\\\\")
+}
+
+/// Guard a line so a leading control character is not interpreted as a request.
+fn roff_guard(line: &str) -> Cow<str> {
+    if line.starts_with('.') || line.starts_with('\'') {
+        Cow::Owned(format!("\\&{line}"))
+    } else {
+        Cow::Borrowed(line)
+    }
+}
+
+/// Render the inline markup of a line as roff: placeholders become italic, URLs
+/// stay literal and inline code is printed as plain text.
+fn render_roff_spans(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for (kind, text) in tokenize(s) {
+        match kind {
+            SpanKind::Placeholder => {
+                out.push_str("\\fI");
+                out.push_str(&escape_roff(text));
+                out.push_str("\\fR");
+            }
+            // Normal text, URLs and inline code are all printed literally.
+            _ => out.push_str(&escape_roff(text)),
+        }
+    }
+
+    out
+}
+
+/// Append `ch` to `out`, escaping the characters that are special in HTML.
+fn push_html_char(out: &mut String, ch: char) {
+    match ch {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        _ => out.push(ch),
+    }
+}
+
+/// Escape a string for inclusion in HTML text.
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        push_html_char(&mut out, ch);
+    }
+    out
+}
+
+/// Render the inline markup of a line as HTML, wrapping placeholders and URLs in
+/// classed elements and inline code in a plain `<code>` element.
+fn render_html_spans(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+
+    for (kind, text) in tokenize(s) {
+        match kind {
+            SpanKind::Normal => out.push_str(&escape_html(text)),
+            SpanKind::Code => {
+                out.push_str("<code>");
+                out.push_str(&escape_html(text));
+                out.push_str("</code>");
+            }
+            SpanKind::Url => {
+                let escaped = escape_html(text);
+                out.push_str(&format!("<a class=\"url\" href=\"{escaped}\">{escaped}</a>"));
+            }
+            SpanKind::Placeholder => {
+                out.push_str("<span class=\"placeholder\">");
+                out.push_str(&escape_html(text));
+                out.push_str("</span>");
             }
         }
+    }
+
+    out
+}
 
-        self.add_newline()?;
-        Ok(self.stdout.flush()?)
+/// Strip inline markup from a line, leaving plain text (placeholder and code
+/// contents without their delimiters).
+fn strip_markup(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for (_, text) in tokenize(s) {
+        out.push_str(text);
+    }
+    out
+}
+
+/// Escape a string for inclusion in a JSON string literal.
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
     }
+    out
 }